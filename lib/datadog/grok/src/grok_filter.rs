@@ -20,6 +20,28 @@ pub enum GrokFilter {
     Lowercase,
     Uppercase,
     Json,
+    DecodeUriComponent,
+    QueryString,
+    KeyValue {
+        key_value_delimiter: String,
+        quotes: String,
+        field_delimiters: Option<String>,
+    },
+    Array {
+        brackets: Option<(String, String)>,
+        separator: String,
+        element_filter: Option<Box<GrokFilter>>,
+    },
+    Xml,
+    Csv {
+        delimiter: char,
+        quote: char,
+        columns: Option<Vec<String>>,
+    },
+    Boolean {
+        true_pattern: Option<String>,
+        false_pattern: Option<String>,
+    },
 }
 
 impl TryFrom<&Function> for GrokFilter {
@@ -47,6 +69,117 @@ impl TryFrom<&Function> for GrokFilter {
             "lowercase" => Ok(GrokFilter::Lowercase),
             "uppercase" => Ok(GrokFilter::Uppercase),
             "json" => Ok(GrokFilter::Json),
+            "decodeuricomponent" => Ok(GrokFilter::DecodeUriComponent),
+            "querystring" => Ok(GrokFilter::QueryString),
+            "keyvalue" => {
+                let as_string = |arg: &FunctionArgument| match arg {
+                    FunctionArgument::Arg(Value::Bytes(b)) => {
+                        Some(String::from_utf8_lossy(b).to_string())
+                    }
+                    _ => None,
+                };
+                let mut args = f.args.iter().flatten();
+                let key_value_delimiter = args
+                    .next()
+                    .and_then(as_string)
+                    .unwrap_or_else(|| "=".to_string());
+                // Datadog's `keyvalue` takes an allowed-characters argument here; we don't yet
+                // support it correctly, so consume the position but ignore its value rather than
+                // silently corrupting unquoted tokens.
+                let _allowed_value_chars = args.next();
+                let quotes = args
+                    .next()
+                    .and_then(as_string)
+                    .unwrap_or_else(|| "\"".to_string());
+                let field_delimiters = args.next().and_then(as_string);
+                Ok(GrokFilter::KeyValue {
+                    key_value_delimiter,
+                    quotes,
+                    field_delimiters,
+                })
+            }
+            "array" => {
+                let mut args = f.args.iter().flatten();
+                let brackets = args.next().and_then(|arg| match arg {
+                    FunctionArgument::Arg(Value::Bytes(b)) => {
+                        let s = String::from_utf8_lossy(b);
+                        let mut chars = s.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(open), Some(close)) => {
+                                Some((open.to_string(), close.to_string()))
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                });
+                let separator = args
+                    .next()
+                    .and_then(|arg| match arg {
+                        FunctionArgument::Arg(Value::Bytes(b)) => {
+                            Some(String::from_utf8_lossy(b).to_string())
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| ",".to_string());
+                let element_filter = args
+                    .next()
+                    .and_then(|arg| match arg {
+                        FunctionArgument::Function(nested) => GrokFilter::try_from(nested).ok(),
+                        _ => None,
+                    })
+                    .map(Box::new);
+                Ok(GrokFilter::Array {
+                    brackets,
+                    separator,
+                    element_filter,
+                })
+            }
+            "xml" => Ok(GrokFilter::Xml),
+            "csv" => {
+                let as_string = |arg: &FunctionArgument| match arg {
+                    FunctionArgument::Arg(Value::Bytes(b)) => {
+                        Some(String::from_utf8_lossy(b).to_string())
+                    }
+                    _ => None,
+                };
+                let mut args = f.args.iter().flatten();
+                let delimiter = args
+                    .next()
+                    .and_then(as_string)
+                    .and_then(|s| s.chars().next())
+                    .unwrap_or(',');
+                let quote = args
+                    .next()
+                    .and_then(as_string)
+                    .and_then(|s| s.chars().next())
+                    .unwrap_or('"');
+                let columns: Vec<String> = args.filter_map(as_string).collect();
+                Ok(GrokFilter::Csv {
+                    delimiter,
+                    quote,
+                    columns: if columns.is_empty() {
+                        None
+                    } else {
+                        Some(columns)
+                    },
+                })
+            }
+            "boolean" => {
+                let as_string = |arg: &FunctionArgument| match arg {
+                    FunctionArgument::Arg(Value::Bytes(b)) => {
+                        Some(String::from_utf8_lossy(b).to_string())
+                    }
+                    _ => None,
+                };
+                let mut args = f.args.iter().flatten();
+                let true_pattern = args.next().and_then(as_string);
+                let false_pattern = args.next().and_then(as_string);
+                Ok(GrokFilter::Boolean {
+                    true_pattern,
+                    false_pattern,
+                })
+            }
             "nullIf" => f
                 .args
                 .as_ref()
@@ -133,7 +266,243 @@ pub fn apply_filter(value: &Value, filter: &GrokFilter) -> Result<Value, GrokRun
                 .map_err(|_e| {
                     GrokRuntimeError::FailedToApplyFilter(filter.to_string(), value.to_string())
                 })
-                .map(|v| v.into()),
+                .map(json_to_value),
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::DecodeUriComponent => match value {
+            Value::Bytes(bytes) => Ok(percent_decode(bytes.as_ref())
+                .decode_utf8_lossy()
+                .into_owned()
+                .into()),
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::QueryString => match value {
+            Value::Bytes(bytes) => {
+                let mut map = std::collections::BTreeMap::new();
+                for pair in String::from_utf8_lossy(bytes).split('&') {
+                    if pair.is_empty() {
+                        continue;
+                    }
+                    let mut kv = pair.splitn(2, '=');
+                    let key =
+                        percent_decode(kv.next().unwrap_or_default().replace('+', " ").as_bytes())
+                            .decode_utf8_lossy()
+                            .into_owned();
+                    let value: Value =
+                        percent_decode(kv.next().unwrap_or_default().replace('+', " ").as_bytes())
+                            .decode_utf8_lossy()
+                            .into_owned()
+                            .into();
+                    match map.remove(&key) {
+                        Some(Value::Array(mut values)) => {
+                            values.push(value);
+                            map.insert(key, Value::Array(values));
+                        }
+                        Some(existing) => {
+                            map.insert(key, Value::Array(vec![existing, value]));
+                        }
+                        None => {
+                            map.insert(key, value);
+                        }
+                    }
+                }
+                Ok(Value::Object(map))
+            }
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::KeyValue {
+            key_value_delimiter,
+            quotes,
+            field_delimiters,
+        } => match value {
+            Value::Bytes(bytes) => {
+                let input = String::from_utf8_lossy(bytes);
+                let field_delimiters: Vec<char> = field_delimiters
+                    .as_deref()
+                    .map(|s| s.chars().collect())
+                    .unwrap_or_else(|| vec![' ', '\t', ',']);
+                let quote_chars: Vec<char> = quotes.chars().collect();
+
+                let mut tokens = Vec::new();
+                let mut token = String::new();
+                let mut in_quote: Option<char> = None;
+                for c in input.chars() {
+                    if let Some(q) = in_quote {
+                        if c == q {
+                            in_quote = None;
+                        } else {
+                            token.push(c);
+                        }
+                    } else if quote_chars.contains(&c) {
+                        in_quote = Some(c);
+                    } else if field_delimiters.contains(&c) {
+                        if !token.is_empty() {
+                            tokens.push(std::mem::take(&mut token));
+                        }
+                    } else {
+                        token.push(c);
+                    }
+                }
+                if !token.is_empty() {
+                    tokens.push(token);
+                }
+
+                let mut map = std::collections::BTreeMap::new();
+                for tok in tokens {
+                    let mut parts = tok.splitn(2, key_value_delimiter.as_str());
+                    let key = parts.next().unwrap_or_default().trim();
+                    let val = match parts.next() {
+                        Some(v) => v.trim(),
+                        None => continue,
+                    };
+                    if key.is_empty() {
+                        continue;
+                    }
+                    let coerced = if let Ok(i) = val.parse::<i64>() {
+                        Value::Integer(i)
+                    } else if let Some(f) = val.parse::<f64>().ok().filter(|f| f.is_finite()) {
+                        NotNan::new(f)
+                            .map(Value::Float)
+                            .unwrap_or_else(|_| val.into())
+                    } else {
+                        val.into()
+                    };
+                    map.insert(key.to_string(), coerced);
+                }
+                Ok(Value::Object(map))
+            }
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::Array {
+            brackets,
+            separator,
+            element_filter,
+        } => match value {
+            Value::Bytes(bytes) => {
+                let mut trimmed = String::from_utf8_lossy(bytes).trim().to_string();
+                if let Some((open, close)) = brackets {
+                    if let Some(stripped) = trimmed.strip_prefix(open.as_str()) {
+                        trimmed = stripped.to_string();
+                    }
+                    if let Some(stripped) = trimmed.strip_suffix(close.as_str()) {
+                        trimmed = stripped.to_string();
+                    }
+                }
+                let mut elements = Vec::new();
+                if !trimmed.is_empty() {
+                    for part in trimmed.split(separator.as_str()) {
+                        let part = part.trim();
+                        let element = match element_filter {
+                            Some(nested) => apply_filter(
+                                &Value::Bytes(part.as_bytes().to_vec().into()),
+                                nested,
+                            )?,
+                            None => part.into(),
+                        };
+                        elements.push(element);
+                    }
+                }
+                Ok(Value::Array(elements))
+            }
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::Xml => match value {
+            Value::Bytes(bytes) => xml_to_value(bytes).map_err(|_e| {
+                GrokRuntimeError::FailedToApplyFilter(filter.to_string(), value.to_string())
+            }),
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::Csv {
+            delimiter,
+            quote,
+            columns,
+        } => match value {
+            Value::Bytes(bytes) => {
+                let input = String::from_utf8_lossy(bytes);
+                let mut fields = Vec::new();
+                let mut field = String::new();
+                let mut in_quotes = false;
+                let mut chars = input.chars().peekable();
+                while let Some(c) = chars.next() {
+                    if in_quotes {
+                        if c == *quote {
+                            if chars.peek() == Some(quote) {
+                                field.push(*quote);
+                                chars.next();
+                            } else {
+                                in_quotes = false;
+                            }
+                        } else {
+                            field.push(c);
+                        }
+                    } else if c == *quote {
+                        in_quotes = true;
+                    } else if c == *delimiter {
+                        fields.push(std::mem::take(&mut field));
+                    } else {
+                        field.push(c);
+                    }
+                }
+                fields.push(field);
+
+                let mut map = std::collections::BTreeMap::new();
+                for (i, field) in fields.into_iter().enumerate() {
+                    let key = columns
+                        .as_ref()
+                        .and_then(|cols| cols.get(i).cloned())
+                        .unwrap_or_else(|| format!("col{}", i + 1));
+                    map.insert(key, field.into());
+                }
+                Ok(Value::Object(map))
+            }
+            _ => Err(GrokRuntimeError::FailedToApplyFilter(
+                filter.to_string(),
+                value.to_string(),
+            )),
+        },
+        GrokFilter::Boolean {
+            true_pattern,
+            false_pattern,
+        } => match value {
+            Value::Bytes(bytes) => {
+                let input = String::from_utf8_lossy(bytes);
+                let is_true = match true_pattern {
+                    Some(pattern) => input.eq_ignore_ascii_case(pattern),
+                    None => matches!(input.as_ref(), "true" | "True" | "TRUE"),
+                };
+                let is_false = match false_pattern {
+                    Some(pattern) => input.eq_ignore_ascii_case(pattern),
+                    None => matches!(input.as_ref(), "false" | "False" | "FALSE"),
+                };
+                if is_true {
+                    Ok(Value::Boolean(true))
+                } else if is_false {
+                    Ok(Value::Boolean(false))
+                } else {
+                    Err(GrokRuntimeError::FailedToApplyFilter(
+                        filter.to_string(),
+                        value.to_string(),
+                    ))
+                }
+            }
             _ => Err(GrokRuntimeError::FailedToApplyFilter(
                 filter.to_string(),
                 value.to_string(),
@@ -154,3 +523,556 @@ pub fn apply_filter(value: &Value, filter: &GrokFilter) -> Result<Value, GrokRun
         },
     }
 }
+
+// serde_json stores in-range integer literals as i64/u64 natively, so preferring
+// `Number::as_i64`/`as_u64` over `as_f64` here keeps every integer that fits in an i64
+// exact instead of losing precision through a blanket float conversion. Unsigned
+// integers above `i64::MAX` do not fit `Value::Integer` and become a lossy `Value::Float`.
+fn json_to_value(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Boolean(b),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::Integer(i)
+            } else if let Some(u) = n.as_u64().filter(|u| *u <= i64::MAX as u64) {
+                Value::Integer(u as i64)
+            } else {
+                Value::Float(
+                    NotNan::new(n.as_f64().unwrap_or(0.0))
+                        .unwrap_or_else(|_| NotNan::new(0.0).expect("not NaN")),
+                )
+            }
+        }
+        serde_json::Value::String(s) => s.into(),
+        serde_json::Value::Array(arr) => Value::Array(arr.into_iter().map(json_to_value).collect()),
+        serde_json::Value::Object(obj) => Value::Object(
+            obj.into_iter()
+                .map(|(k, v)| (k, json_to_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+// Repeated child elements collapse into a `Value::Array`, mirroring how `QueryString`
+// handles repeated keys.
+fn insert_xml_child(
+    map: &mut std::collections::BTreeMap<String, Value>,
+    key: String,
+    value: Value,
+) {
+    match map.remove(&key) {
+        Some(Value::Array(mut values)) => {
+            values.push(value);
+            map.insert(key, Value::Array(values));
+        }
+        Some(existing) => {
+            map.insert(key, Value::Array(vec![existing, value]));
+        }
+        None => {
+            map.insert(key, value);
+        }
+    }
+}
+
+// A small hand-rolled XML parser: the grok crate has no XML dependency vendored,
+// so rather than adding one blind we parse the subset of XML this filter needs
+// (elements, attributes, nested/repeated children, text) ourselves. Every malformed
+// input path returns an `Err` instead of panicking, since a bad grok match must
+// surface as `FailedToApplyFilter`, not take down the pipeline.
+fn xml_to_value(bytes: &[u8]) -> Result<Value, String> {
+    let chars: Vec<char> = String::from_utf8_lossy(bytes).chars().collect();
+    let mut pos = 0;
+    xml_skip_trivia(&chars, &mut pos);
+    let (name, value) = xml_parse_element(&chars, &mut pos)?;
+    xml_skip_trivia(&chars, &mut pos);
+    if pos != chars.len() {
+        return Err("unexpected content after the root element".to_string());
+    }
+    let mut document = std::collections::BTreeMap::new();
+    document.insert(name, value);
+    Ok(Value::Object(document))
+}
+
+fn xml_skip_ws(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
+
+// Skips whitespace, the `<?xml ... ?>` declaration, comments, and doctype declarations.
+fn xml_skip_trivia(chars: &[char], pos: &mut usize) {
+    loop {
+        xml_skip_ws(chars, pos);
+        if chars.get(*pos) == Some(&'<') && chars.get(*pos + 1) == Some(&'?') {
+            while *pos < chars.len() && !(chars[*pos] == '?' && chars.get(*pos + 1) == Some(&'>')) {
+                *pos += 1;
+            }
+            *pos = (*pos + 2).min(chars.len());
+        } else if chars.get(*pos) == Some(&'<')
+            && chars.get(*pos + 1) == Some(&'!')
+            && chars.get(*pos + 2) == Some(&'-')
+            && chars.get(*pos + 3) == Some(&'-')
+        {
+            *pos += 4;
+            while *pos < chars.len()
+                && !(chars[*pos] == '-'
+                    && chars.get(*pos + 1) == Some(&'-')
+                    && chars.get(*pos + 2) == Some(&'>'))
+            {
+                *pos += 1;
+            }
+            *pos = (*pos + 3).min(chars.len());
+        } else if chars.get(*pos) == Some(&'<') && chars.get(*pos + 1) == Some(&'!') {
+            while *pos < chars.len() && chars[*pos] != '>' {
+                *pos += 1;
+            }
+            *pos = (*pos + 1).min(chars.len());
+        } else {
+            break;
+        }
+    }
+}
+
+fn xml_parse_name(chars: &[char], pos: &mut usize) -> Result<String, String> {
+    let start = *pos;
+    while *pos < chars.len()
+        && !chars[*pos].is_whitespace()
+        && !matches!(chars[*pos], '>' | '/' | '=')
+    {
+        *pos += 1;
+    }
+    if *pos == start {
+        return Err("expected an element or attribute name".to_string());
+    }
+    Ok(chars[start..*pos].iter().collect())
+}
+
+fn xml_unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn xml_parse_attributes(
+    chars: &[char],
+    pos: &mut usize,
+) -> Result<std::collections::BTreeMap<String, Value>, String> {
+    let mut attrs = std::collections::BTreeMap::new();
+    loop {
+        xml_skip_ws(chars, pos);
+        match chars.get(*pos) {
+            Some('>') | Some('/') => break,
+            None => return Err("unexpected end of input while parsing a start tag".to_string()),
+            _ => {}
+        }
+        let name = xml_parse_name(chars, pos)?;
+        xml_skip_ws(chars, pos);
+        if chars.get(*pos) != Some(&'=') {
+            return Err(format!("expected `=` after attribute `{}`", name));
+        }
+        *pos += 1;
+        xml_skip_ws(chars, pos);
+        let quote = *chars.get(*pos).ok_or_else(|| {
+            "unexpected end of input while parsing an attribute value".to_string()
+        })?;
+        if quote != '"' && quote != '\'' {
+            return Err("attribute values must be quoted".to_string());
+        }
+        *pos += 1;
+        let start = *pos;
+        while *pos < chars.len() && chars[*pos] != quote {
+            *pos += 1;
+        }
+        if *pos >= chars.len() {
+            return Err(format!("unterminated value for attribute `{}`", name));
+        }
+        let raw: String = chars[start..*pos].iter().collect();
+        *pos += 1;
+        attrs.insert(format!("@{}", name), xml_unescape(&raw).into());
+    }
+    Ok(attrs)
+}
+
+fn xml_parse_element(chars: &[char], pos: &mut usize) -> Result<(String, Value), String> {
+    if chars.get(*pos) != Some(&'<') {
+        return Err("expected `<` to start an element".to_string());
+    }
+    *pos += 1;
+    let name = xml_parse_name(chars, pos)?;
+    let mut attrs = xml_parse_attributes(chars, pos)?;
+
+    if chars.get(*pos) == Some(&'/') {
+        *pos += 1;
+        if chars.get(*pos) != Some(&'>') {
+            return Err(format!(
+                "expected `>` to close self-closing element `{}`",
+                name
+            ));
+        }
+        *pos += 1;
+        let value = if attrs.is_empty() {
+            "".into()
+        } else {
+            Value::Object(attrs)
+        };
+        return Ok((name, value));
+    }
+    if chars.get(*pos) != Some(&'>') {
+        return Err(format!("expected `>` to close start tag `{}`", name));
+    }
+    *pos += 1;
+
+    let mut children: std::collections::BTreeMap<String, Value> = std::collections::BTreeMap::new();
+    let mut text = String::new();
+    loop {
+        if *pos >= chars.len() {
+            return Err(format!("unexpected end of input inside element `{}`", name));
+        }
+        if chars[*pos] != '<' {
+            let start = *pos;
+            while *pos < chars.len() && chars[*pos] != '<' {
+                *pos += 1;
+            }
+            text.push_str(&xml_unescape(
+                &chars[start..*pos].iter().collect::<String>(),
+            ));
+            continue;
+        }
+        if chars.get(*pos + 1) == Some(&'/') {
+            let mut close_pos = *pos + 2;
+            let close_name = xml_parse_name(chars, &mut close_pos)?;
+            xml_skip_ws(chars, &mut close_pos);
+            if chars.get(close_pos) != Some(&'>') {
+                return Err(format!("expected `>` to close end tag `</{}`", close_name));
+            }
+            if close_name != name {
+                return Err(format!(
+                    "mismatched closing tag: expected `</{}>`, found `</{}>`",
+                    name, close_name
+                ));
+            }
+            *pos = close_pos + 1;
+            break;
+        }
+        if chars.get(*pos + 1) == Some(&'!') {
+            let before = *pos;
+            xml_skip_trivia(chars, pos);
+            if *pos == before {
+                return Err(format!("unexpected `<!` inside element `{}`", name));
+            }
+            continue;
+        }
+        let (child_name, child_value) = xml_parse_element(chars, pos)?;
+        insert_xml_child(&mut children, child_name, child_value);
+    }
+
+    let trimmed_text = text.trim();
+    let value = if children.is_empty() && attrs.is_empty() {
+        trimmed_text.into()
+    } else {
+        if !trimmed_text.is_empty() {
+            children.insert("#text".to_string(), trimmed_text.into());
+        }
+        attrs.append(&mut children);
+        Value::Object(attrs)
+    };
+    Ok((name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodeuricomponent_decodes_percent_escapes() {
+        let value = Value::Bytes("hello%20world%21".into());
+        let result = apply_filter(&value, &GrokFilter::DecodeUriComponent).unwrap();
+        assert_eq!(result, Value::Bytes("hello world!".into()));
+    }
+
+    #[test]
+    fn querystring_collapses_repeated_keys_into_an_array() {
+        let value = Value::Bytes("a=1&b=2&b=3".into());
+        let result = apply_filter(&value, &GrokFilter::QueryString).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("a"), Some(&Value::Bytes("1".into())));
+                match map.get("b") {
+                    Some(Value::Array(values)) => assert_eq!(values.len(), 2),
+                    other => panic!(
+                        "expected repeated `b` to collapse into an array, got {:?}",
+                        other
+                    ),
+                }
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn querystring_decodes_plus_as_space() {
+        let value = Value::Bytes("q=hello+world".into());
+        let result = apply_filter(&value, &GrokFilter::QueryString).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("q"), Some(&Value::Bytes("hello world".into())));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    fn keyvalue_filter() -> GrokFilter {
+        GrokFilter::KeyValue {
+            key_value_delimiter: "=".to_string(),
+            quotes: "\"".to_string(),
+            field_delimiters: None,
+        }
+    }
+
+    #[test]
+    fn parses_keyvalue_pairs() {
+        let value = Value::Bytes("a=1 b=2".into());
+        let result = apply_filter(&value, &keyvalue_filter()).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("a"), Some(&Value::Integer(1)));
+                assert_eq!(map.get("b"), Some(&Value::Integer(2)));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn parses_quoted_keyvalue_values() {
+        let value = Value::Bytes("key=\"a, b\" other=c".into());
+        let result = apply_filter(&value, &keyvalue_filter()).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("key"), Some(&Value::Bytes("a, b".into())));
+                assert_eq!(map.get("other"), Some(&Value::Bytes("c".into())));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn skips_keyvalue_pairs_with_missing_values() {
+        let value = Value::Bytes("a=1 standalone b=2".into());
+        let result = apply_filter(&value, &keyvalue_filter()).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert!(!map.contains_key("standalone"));
+                assert_eq!(map.get("a"), Some(&Value::Integer(1)));
+                assert_eq!(map.get("b"), Some(&Value::Integer(2)));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn last_keyvalue_pair_wins_on_duplicate_keys() {
+        let value = Value::Bytes("a=1 a=2".into());
+        let result = apply_filter(&value, &keyvalue_filter()).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("a"), Some(&Value::Integer(2)));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn keyvalue_keeps_non_numeric_words_like_nan_and_inf_as_strings() {
+        let value = Value::Bytes("x=NaN y=inf z=-infinity".into());
+        let result = apply_filter(&value, &keyvalue_filter()).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("x"), Some(&Value::Bytes("NaN".into())));
+                assert_eq!(map.get("y"), Some(&Value::Bytes("inf".into())));
+                assert_eq!(map.get("z"), Some(&Value::Bytes("-infinity".into())));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn array_splits_and_strips_brackets() {
+        let filter = GrokFilter::Array {
+            brackets: Some(("[".to_string(), "]".to_string())),
+            separator: ",".to_string(),
+            element_filter: None,
+        };
+        let value = Value::Bytes("[a, b, c]".into());
+        let result = apply_filter(&value, &filter).unwrap();
+        match result {
+            Value::Array(elements) => assert_eq!(
+                elements,
+                vec![
+                    Value::Bytes("a".into()),
+                    Value::Bytes("b".into()),
+                    Value::Bytes("c".into()),
+                ]
+            ),
+            _ => panic!("expected an array"),
+        }
+    }
+
+    #[test]
+    fn array_applies_nested_element_filter() {
+        let filter = GrokFilter::Array {
+            brackets: Some(("[".to_string(), "]".to_string())),
+            separator: ";".to_string(),
+            element_filter: Some(Box::new(GrokFilter::Integer)),
+        };
+        let value = Value::Bytes("[1;2;3]".into());
+        let result = apply_filter(&value, &filter).unwrap();
+        match result {
+            Value::Array(elements) => assert_eq!(
+                elements,
+                vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)]
+            ),
+            _ => panic!("expected an array"),
+        }
+    }
+
+    #[test]
+    fn array_of_empty_input_is_empty_not_single_blank_element() {
+        let filter = GrokFilter::Array {
+            brackets: Some(("[".to_string(), "]".to_string())),
+            separator: ",".to_string(),
+            element_filter: None,
+        };
+        let value = Value::Bytes("[]".into());
+        let result = apply_filter(&value, &filter).unwrap();
+        match result {
+            Value::Array(elements) => assert!(elements.is_empty()),
+            _ => panic!("expected an array"),
+        }
+    }
+
+    #[test]
+    fn json_preserves_large_integers_exactly() {
+        let value = Value::Bytes(r#"{"id": 9223372036854775807}"#.into());
+        let result = apply_filter(&value, &GrokFilter::Json).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("id"), Some(&Value::Integer(9223372036854775807)));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn json_returns_error_on_malformed_input() {
+        let value = Value::Bytes("{not json".into());
+        assert!(apply_filter(&value, &GrokFilter::Json).is_err());
+    }
+
+    #[test]
+    fn parses_csv_record_with_quoted_field() {
+        let filter = GrokFilter::Csv {
+            delimiter: ',',
+            quote: '"',
+            columns: Some(vec!["a".to_string(), "b".to_string()]),
+        };
+        let value = Value::Bytes("1,\"two, and more\"".into());
+        let result = apply_filter(&value, &filter).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("a"), Some(&Value::Bytes("1".into())));
+                assert_eq!(map.get("b"), Some(&Value::Bytes("two, and more".into())));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn parses_csv_record_with_positional_columns() {
+        let filter = GrokFilter::Csv {
+            delimiter: ',',
+            quote: '"',
+            columns: None,
+        };
+        let value = Value::Bytes("1,2".into());
+        let result = apply_filter(&value, &filter).unwrap();
+        match result {
+            Value::Object(map) => {
+                assert_eq!(map.get("col1"), Some(&Value::Bytes("1".into())));
+                assert_eq!(map.get("col2"), Some(&Value::Bytes("2".into())));
+            }
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn parses_xml_with_attributes_and_repeated_children() {
+        let value = Value::Bytes(r#"<root a="1"><item>x</item><item>y</item></root>"#.into());
+        let result = apply_filter(&value, &GrokFilter::Xml).unwrap();
+        match result {
+            Value::Object(document) => match document.get("root") {
+                Some(Value::Object(root)) => {
+                    assert_eq!(root.get("@a"), Some(&Value::Bytes("1".into())));
+                    match root.get("item") {
+                        Some(Value::Array(items)) => assert_eq!(items.len(), 2),
+                        other => panic!(
+                            "expected repeated <item> to collapse into an array, got {:?}",
+                            other
+                        ),
+                    }
+                }
+                other => panic!("expected a `root` object, got {:?}", other),
+            },
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn xml_returns_error_on_malformed_input() {
+        let value = Value::Bytes("<root><unclosed></root>".into());
+        assert!(apply_filter(&value, &GrokFilter::Xml).is_err());
+    }
+
+    #[test]
+    fn boolean_uses_default_true_false_patterns() {
+        let filter = GrokFilter::Boolean {
+            true_pattern: None,
+            false_pattern: None,
+        };
+        assert!(matches!(
+            apply_filter(&Value::Bytes("True".into()), &filter),
+            Ok(Value::Boolean(true))
+        ));
+        assert!(matches!(
+            apply_filter(&Value::Bytes("FALSE".into()), &filter),
+            Ok(Value::Boolean(false))
+        ));
+    }
+
+    #[test]
+    fn boolean_uses_custom_patterns() {
+        let filter = GrokFilter::Boolean {
+            true_pattern: Some("yes".to_string()),
+            false_pattern: Some("no".to_string()),
+        };
+        assert!(matches!(
+            apply_filter(&Value::Bytes("YES".into()), &filter),
+            Ok(Value::Boolean(true))
+        ));
+        assert!(matches!(
+            apply_filter(&Value::Bytes("no".into()), &filter),
+            Ok(Value::Boolean(false))
+        ));
+    }
+
+    #[test]
+    fn boolean_fails_on_non_matching_input() {
+        let filter = GrokFilter::Boolean {
+            true_pattern: None,
+            false_pattern: None,
+        };
+        assert!(apply_filter(&Value::Bytes("maybe".into()), &filter).is_err());
+    }
+}